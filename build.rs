@@ -1,38 +1,58 @@
-use std::env;
-use std::fs;
-use std::path::PathBuf;
-
-fn main() {
-    let dll_filename = "kyber.dll"; // The DLL file is in the root directory
-
-    // Get the build output directory
-    let out_dir = PathBuf::from(env::var("OUT_DIR").unwrap());
-
-    // Define the path to the DLL in the root directory
-    let dll_src_path = PathBuf::from(dll_filename);
-
-    // Define where to copy the DLL in the build output directory
-    let dll_dest_path = out_dir.join(dll_filename);
-
-    // Check if the DLL file exists before trying to copy
-    if !dll_src_path.exists() {
-        panic!("DLL file not found at {}", dll_src_path.display());
-    }
-
-    // Copy the DLL from the root folder to the build output directory
-    fs::copy(&dll_src_path, &dll_dest_path).expect(&format!(
-        "Failed to copy {} to {}",
-        dll_src_path.display(),
-        dll_dest_path.display()
-    ));
-
-    // Tell Cargo to link to the DLL in the output directory
-    println!("cargo:rustc-link-search=native={}", out_dir.display());
-
-    // Print information for debugging
-    println!("cargo:warning=Copied DLL to: {}", dll_dest_path.display());
-
-    // Make sure the build script reruns if the DLL or the build script changes
-    println!("cargo:rerun-if-changed=build.rs");
-    println!("cargo:rerun-if-changed={}", dll_src_path.display());
-}
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+
+/// Picks the shared-library filename this platform's dynamic loader expects.
+fn platform_lib_filename() -> &'static str {
+    if cfg!(target_os = "windows") {
+        "kyber.dll"
+    } else if cfg!(target_os = "macos") {
+        "libkyber.dylib"
+    } else {
+        "libkyber.so"
+    }
+}
+
+fn main() {
+    let lib_filename = platform_lib_filename();
+
+    // Get the build output directory
+    let out_dir = PathBuf::from(env::var("OUT_DIR").unwrap());
+
+    // Define the path to the shared library in the root directory
+    let lib_src_path = PathBuf::from(lib_filename);
+
+    // Define where to copy the shared library in the build output directory
+    let lib_dest_path = out_dir.join(lib_filename);
+
+    // The library is optional at build time: it's only needed at runtime, and
+    // KYBER_LIB_PATH (or the system loader's search path) can point at a copy
+    // that lives outside the crate root entirely. Copying it into OUT_DIR is
+    // just a convenience so a crate-root-local build is self-contained; don't
+    // fail the build over its absence here, since doing so would make the
+    // KYBER_LIB_PATH override case (the whole point of request chunk0-2)
+    // impossible to build in the first place.
+    if lib_src_path.exists() {
+        fs::copy(&lib_src_path, &lib_dest_path).unwrap_or_else(|_| {
+            panic!("Failed to copy {} to {}", lib_src_path.display(), lib_dest_path.display())
+        });
+
+        // Tell Cargo to link to the shared library in the output directory
+        println!("cargo:rustc-link-search=native={}", out_dir.display());
+
+        // Let lib.rs locate the copy at runtime via candidate_lib_paths()
+        println!("cargo:rustc-env=KYBER_BUILD_OUT_DIR={}", out_dir.display());
+
+        // Print information for debugging
+        println!("cargo:warning=Copied {} to: {}", lib_filename, lib_dest_path.display());
+    } else {
+        println!(
+            "cargo:warning={} not found in the crate root; set KYBER_LIB_PATH at runtime to point at it",
+            lib_filename
+        );
+    }
+
+    // Make sure the build script reruns if the shared library or the build script changes
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-changed={}", lib_src_path.display());
+}
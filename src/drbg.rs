@@ -0,0 +1,188 @@
+//! Minimal AES-256-CTR DRBG, used only to derive the deterministic `coins`
+//! consumed by [`crate::generate_keypair_deterministic`] and
+//! [`crate::encapsulate_deterministic`] from a KAT `seed`.
+//!
+//! This mirrors the (non-reseeding, no additional-input) `AES256_CTR_DRBG`
+//! implementation in the NIST PQC submission's `rng.c`, which is what the
+//! reference KAT generator (`PQCgenKAT_kem.c`) uses to turn a 48-byte seed
+//! into the random coins fed to `crypto_kem_keypair`/`crypto_kem_enc`.
+
+const SBOX: [u8; 256] = [
+    0x63, 0x7c, 0x77, 0x7b, 0xf2, 0x6b, 0x6f, 0xc5, 0x30, 0x01, 0x67, 0x2b, 0xfe, 0xd7, 0xab, 0x76,
+    0xca, 0x82, 0xc9, 0x7d, 0xfa, 0x59, 0x47, 0xf0, 0xad, 0xd4, 0xa2, 0xaf, 0x9c, 0xa4, 0x72, 0xc0,
+    0xb7, 0xfd, 0x93, 0x26, 0x36, 0x3f, 0xf7, 0xcc, 0x34, 0xa5, 0xe5, 0xf1, 0x71, 0xd8, 0x31, 0x15,
+    0x04, 0xc7, 0x23, 0xc3, 0x18, 0x96, 0x05, 0x9a, 0x07, 0x12, 0x80, 0xe2, 0xeb, 0x27, 0xb2, 0x75,
+    0x09, 0x83, 0x2c, 0x1a, 0x1b, 0x6e, 0x5a, 0xa0, 0x52, 0x3b, 0xd6, 0xb3, 0x29, 0xe3, 0x2f, 0x84,
+    0x53, 0xd1, 0x00, 0xed, 0x20, 0xfc, 0xb1, 0x5b, 0x6a, 0xcb, 0xbe, 0x39, 0x4a, 0x4c, 0x58, 0xcf,
+    0xd0, 0xef, 0xaa, 0xfb, 0x43, 0x4d, 0x33, 0x85, 0x45, 0xf9, 0x02, 0x7f, 0x50, 0x3c, 0x9f, 0xa8,
+    0x51, 0xa3, 0x40, 0x8f, 0x92, 0x9d, 0x38, 0xf5, 0xbc, 0xb6, 0xda, 0x21, 0x10, 0xff, 0xf3, 0xd2,
+    0xcd, 0x0c, 0x13, 0xec, 0x5f, 0x97, 0x44, 0x17, 0xc4, 0xa7, 0x7e, 0x3d, 0x64, 0x5d, 0x19, 0x73,
+    0x60, 0x81, 0x4f, 0xdc, 0x22, 0x2a, 0x90, 0x88, 0x46, 0xee, 0xb8, 0x14, 0xde, 0x5e, 0x0b, 0xdb,
+    0xe0, 0x32, 0x3a, 0x0a, 0x49, 0x06, 0x24, 0x5c, 0xc2, 0xd3, 0xac, 0x62, 0x91, 0x95, 0xe4, 0x79,
+    0xe7, 0xc8, 0x37, 0x6d, 0x8d, 0xd5, 0x4e, 0xa9, 0x6c, 0x56, 0xf4, 0xea, 0x65, 0x7a, 0xae, 0x08,
+    0xba, 0x78, 0x25, 0x2e, 0x1c, 0xa6, 0xb4, 0xc6, 0xe8, 0xdd, 0x74, 0x1f, 0x4b, 0xbd, 0x8b, 0x8a,
+    0x70, 0x3e, 0xb5, 0x66, 0x48, 0x03, 0xf6, 0x0e, 0x61, 0x35, 0x57, 0xb9, 0x86, 0xc1, 0x1d, 0x9e,
+    0xe1, 0xf8, 0x98, 0x11, 0x69, 0xd9, 0x8e, 0x94, 0x9b, 0x1e, 0x87, 0xe9, 0xce, 0x55, 0x28, 0xdf,
+    0x8c, 0xa1, 0x89, 0x0d, 0xbf, 0xe6, 0x42, 0x68, 0x41, 0x99, 0x2d, 0x0f, 0xb0, 0x54, 0xbb, 0x16,
+];
+
+const RCON: [u32; 7] = [
+    0x01000000, 0x02000000, 0x04000000, 0x08000000, 0x10000000, 0x20000000, 0x40000000,
+];
+
+fn sub_byte(b: u8) -> u8 {
+    SBOX[b as usize]
+}
+
+fn sub_word(w: u32) -> u32 {
+    let b = w.to_be_bytes();
+    u32::from_be_bytes([sub_byte(b[0]), sub_byte(b[1]), sub_byte(b[2]), sub_byte(b[3])])
+}
+
+/// Expands a 256-bit key into the 60 round-key words AES-256 needs (14 rounds).
+fn key_expansion(key: &[u8; 32]) -> [u32; 60] {
+    const NK: usize = 8;
+    let mut w = [0u32; 60];
+    for i in 0..NK {
+        w[i] = u32::from_be_bytes([key[4 * i], key[4 * i + 1], key[4 * i + 2], key[4 * i + 3]]);
+    }
+    for i in NK..60 {
+        let mut temp = w[i - 1];
+        if i % NK == 0 {
+            temp = sub_word(temp.rotate_left(8)) ^ RCON[i / NK - 1];
+        } else if i % NK == 4 {
+            temp = sub_word(temp);
+        }
+        w[i] = w[i - NK] ^ temp;
+    }
+    w
+}
+
+fn sub_bytes(state: &mut [u8; 16]) {
+    for b in state.iter_mut() {
+        *b = sub_byte(*b);
+    }
+}
+
+fn shift_rows(state: &mut [u8; 16]) {
+    let s = *state;
+    for r in 0..4 {
+        for c in 0..4 {
+            state[c * 4 + r] = s[((c + r) % 4) * 4 + r];
+        }
+    }
+}
+
+fn xtime(x: u8) -> u8 {
+    let hi = x & 0x80;
+    let shifted = x << 1;
+    if hi != 0 {
+        shifted ^ 0x1b
+    } else {
+        shifted
+    }
+}
+
+fn mix_columns(state: &mut [u8; 16]) {
+    for c in 0..4 {
+        let a0 = state[c * 4];
+        let a1 = state[c * 4 + 1];
+        let a2 = state[c * 4 + 2];
+        let a3 = state[c * 4 + 3];
+        state[c * 4] = xtime(a0) ^ (xtime(a1) ^ a1) ^ a2 ^ a3;
+        state[c * 4 + 1] = a0 ^ xtime(a1) ^ (xtime(a2) ^ a2) ^ a3;
+        state[c * 4 + 2] = a0 ^ a1 ^ xtime(a2) ^ (xtime(a3) ^ a3);
+        state[c * 4 + 3] = (xtime(a0) ^ a0) ^ a1 ^ a2 ^ xtime(a3);
+    }
+}
+
+fn add_round_key(state: &mut [u8; 16], words: &[u32]) {
+    for c in 0..4 {
+        let bytes = words[c].to_be_bytes();
+        for r in 0..4 {
+            state[c * 4 + r] ^= bytes[r];
+        }
+    }
+}
+
+/// Encrypts a single 16-byte block under an AES-256 key schedule.
+fn encrypt_block(round_keys: &[u32; 60], input: &[u8; 16]) -> [u8; 16] {
+    const NR: usize = 14;
+    let mut state = *input;
+    add_round_key(&mut state, &round_keys[0..4]);
+    for round in 1..NR {
+        sub_bytes(&mut state);
+        shift_rows(&mut state);
+        mix_columns(&mut state);
+        add_round_key(&mut state, &round_keys[round * 4..round * 4 + 4]);
+    }
+    sub_bytes(&mut state);
+    shift_rows(&mut state);
+    add_round_key(&mut state, &round_keys[NR * 4..NR * 4 + 4]);
+    state
+}
+
+fn increment_v(v: &mut [u8; 16]) {
+    for byte in v.iter_mut().rev() {
+        if *byte == 0xff {
+            *byte = 0;
+        } else {
+            *byte += 1;
+            break;
+        }
+    }
+}
+
+/// AES-256-CTR DRBG with no derivation function and no additional input,
+/// matching the NIST KAT reference `rng.c`.
+pub struct Aes256CtrDrbg {
+    key: [u8; 32],
+    v: [u8; 16],
+}
+
+impl Aes256CtrDrbg {
+    /// Seeds a fresh DRBG from a 48-byte seed, per `randombytes_init`:
+    /// `Key`/`V` start at all-zero and are immediately updated with the seed.
+    pub fn new(seed: &[u8; 48]) -> Self {
+        let mut drbg = Aes256CtrDrbg {
+            key: [0u8; 32],
+            v: [0u8; 16],
+        };
+        drbg.update(Some(seed));
+        drbg
+    }
+
+    /// `AES256_CTR_DRBG_Update`: derives 48 bytes of new `Key || V` by
+    /// encrypting three successive increments of `V` under the current key,
+    /// optionally XORing the result with `provided_data`.
+    fn update(&mut self, provided_data: Option<&[u8; 48]>) {
+        let round_keys = key_expansion(&self.key);
+        let mut temp = [0u8; 48];
+        for block in temp.chunks_exact_mut(16) {
+            increment_v(&mut self.v);
+            block.copy_from_slice(&encrypt_block(&round_keys, &self.v));
+        }
+        if let Some(data) = provided_data {
+            for (t, d) in temp.iter_mut().zip(data.iter()) {
+                *t ^= d;
+            }
+        }
+        self.key.copy_from_slice(&temp[0..32]);
+        self.v.copy_from_slice(&temp[32..48]);
+    }
+
+    /// Draws `len` pseudorandom bytes, then runs an unprovided `update` so the
+    /// next call (and any subsequent KAT entry's reseed) starts from fresh state.
+    pub fn next_bytes(&mut self, len: usize) -> Vec<u8> {
+        let round_keys = key_expansion(&self.key);
+        let mut out = Vec::with_capacity(len);
+        while out.len() < len {
+            increment_v(&mut self.v);
+            let block = encrypt_block(&round_keys, &self.v);
+            let take = (len - out.len()).min(16);
+            out.extend_from_slice(&block[..take]);
+        }
+        self.update(None);
+        out
+    }
+}
@@ -0,0 +1,39 @@
+//! Error type returned by this crate's public API.
+
+use std::fmt;
+
+/// Errors that can occur while loading the Kyber reference library or
+/// performing a KEM operation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum KyberError {
+    /// The platform shared library (`kyber.dll` / `libkyber.so` / `libkyber.dylib`,
+    /// or the path given via `KYBER_LIB_PATH`) could not be loaded.
+    LibraryLoadFailed(String),
+    /// A required `pqcrystals_kyber*_ref_*` symbol was not found in the loaded library.
+    SymbolMissing(String),
+    /// A caller-supplied buffer (public key, secret key, or ciphertext) had the wrong length.
+    InvalidInput(String),
+    /// `crypto_kem_keypair` returned a non-zero error code.
+    KeypairGeneration(i32),
+    /// `crypto_kem_enc` returned a non-zero error code.
+    Encapsulation(i32),
+    /// `crypto_kem_dec` returned a non-zero error code.
+    Decapsulation(i32),
+}
+
+impl fmt::Display for KyberError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            KyberError::LibraryLoadFailed(msg) => write!(f, "failed to load Kyber library: {}", msg),
+            KyberError::SymbolMissing(name) => write!(f, "missing symbol in Kyber library: {}", name),
+            KyberError::InvalidInput(msg) => write!(f, "invalid input: {}", msg),
+            KyberError::KeypairGeneration(code) => {
+                write!(f, "keypair generation failed with error code: {}", code)
+            }
+            KyberError::Encapsulation(code) => write!(f, "encapsulation failed with error code: {}", code),
+            KyberError::Decapsulation(code) => write!(f, "decapsulation failed with error code: {}", code),
+        }
+    }
+}
+
+impl std::error::Error for KyberError {}
@@ -0,0 +1,130 @@
+//! Hybrid X25519 + Kyber768 key encapsulation.
+//!
+//! [NIST's post-quantum migration guidance][pqc] recommends deploying Kyber
+//! in a hybrid alongside a classical exchange, so the connection stays
+//! secure even if one of the two primitives turns out to be broken. This
+//! module runs an X25519 exchange (via `x25519-dalek`) alongside this
+//! crate's Kyber768 calls, keeps the two public keys side by side in a
+//! [`HybridPublicKey`] (no single concatenated public-key encoding is
+//! provided), concatenates the two ciphertexts, and
+//! derives the final 32-byte shared secret with `SHAKE256(x25519_ss ||
+//! kyber_ss)` truncated to 32 bytes — an order-fixed KDF, so callers on both
+//! ends must agree on the X25519-then-Kyber concatenation order used here.
+//!
+//! [pqc]: https://csrc.nist.gov/projects/post-quantum-cryptography
+//!
+//! The X25519 shared secret is classical and must not outlive this module:
+//! it is zeroized as soon as it has been folded into the combined secret.
+
+use crate::{
+    decapsulate, encapsulate, generate_keypair, Ciphertext, KyberError, KyberLevel, PublicKey as KyberPublicKey,
+    SecretKey as KyberSecretKey, SharedSecret,
+};
+
+use rand_core::OsRng;
+use sha3::digest::{ExtendableOutput, Update, XofReader};
+use sha3::Shake256;
+use x25519_dalek::{EphemeralSecret, PublicKey as X25519PublicKey, StaticSecret};
+use zeroize::Zeroize;
+
+/// Size in bytes of a raw X25519 public key (and of the ephemeral public key
+/// prefix in a hybrid ciphertext).
+pub const X25519_PUBLIC_KEY_BYTES: usize = 32;
+
+/// The combined public key for a hybrid keypair: an X25519 public key plus a
+/// Kyber768 public key.
+pub struct HybridPublicKey {
+    pub x25519: X25519PublicKey,
+    pub kyber: KyberPublicKey,
+}
+
+/// The combined secret key for a hybrid keypair.
+pub struct HybridSecretKey {
+    x25519: StaticSecret,
+    kyber: KyberSecretKey,
+}
+
+/// A matched hybrid public/secret keypair.
+pub struct HybridKeypair {
+    pub public: HybridPublicKey,
+    pub secret: HybridSecretKey,
+}
+
+/// Generates a hybrid X25519 + Kyber768 keypair.
+pub fn hybrid_keypair() -> Result<HybridKeypair, KyberError> {
+    let x25519_secret = StaticSecret::random_from_rng(OsRng);
+    let x25519_public = X25519PublicKey::from(&x25519_secret);
+    let kyber = generate_keypair(KyberLevel::Kyber768)?;
+
+    Ok(HybridKeypair {
+        public: HybridPublicKey {
+            x25519: x25519_public,
+            kyber: kyber.public,
+        },
+        secret: HybridSecretKey {
+            x25519: x25519_secret,
+            kyber: kyber.secret,
+        },
+    })
+}
+
+/// Encapsulates a hybrid shared secret against a peer's [`HybridPublicKey`].
+///
+/// Returns the combined ciphertext (the ephemeral X25519 public key followed
+/// by the Kyber768 ciphertext) and the derived shared secret.
+pub fn hybrid_encapsulate(peer: &HybridPublicKey) -> Result<(Vec<u8>, SharedSecret), KyberError> {
+    let ephemeral_secret = EphemeralSecret::random_from_rng(OsRng);
+    let ephemeral_public = X25519PublicKey::from(&ephemeral_secret);
+
+    let (kyber_ct, kyber_ss) = encapsulate(KyberLevel::Kyber768, &peer.kyber)?;
+    let mut x25519_ss = ephemeral_secret.diffie_hellman(&peer.x25519).to_bytes();
+
+    let combined = combine_secrets(&x25519_ss, kyber_ss.as_bytes());
+    x25519_ss.zeroize();
+
+    let mut ciphertext = Vec::with_capacity(X25519_PUBLIC_KEY_BYTES + kyber_ct.as_bytes().len());
+    ciphertext.extend_from_slice(ephemeral_public.as_bytes());
+    ciphertext.extend_from_slice(kyber_ct.as_bytes());
+
+    Ok((ciphertext, combined))
+}
+
+/// Decapsulates a hybrid shared secret produced by [`hybrid_encapsulate`].
+///
+/// `ciphertext` must be the X25519 ephemeral public key (the first
+/// [`X25519_PUBLIC_KEY_BYTES`] bytes) followed by the Kyber768 ciphertext.
+pub fn hybrid_decapsulate(secret: &HybridSecretKey, ciphertext: &[u8]) -> Result<SharedSecret, KyberError> {
+    if ciphertext.len() <= X25519_PUBLIC_KEY_BYTES {
+        return Err(KyberError::InvalidInput(format!(
+            "hybrid ciphertext too short: expected more than {} bytes, got {}",
+            X25519_PUBLIC_KEY_BYTES,
+            ciphertext.len()
+        )));
+    }
+    let (x25519_ct, kyber_ct_bytes) = ciphertext.split_at(X25519_PUBLIC_KEY_BYTES);
+
+    let peer_ephemeral_bytes: [u8; X25519_PUBLIC_KEY_BYTES] = x25519_ct
+        .try_into()
+        .map_err(|_| KyberError::InvalidInput("malformed X25519 ephemeral public key".into()))?;
+    let peer_ephemeral = X25519PublicKey::from(peer_ephemeral_bytes);
+
+    let kyber_ct = Ciphertext::from_bytes(kyber_ct_bytes, KyberLevel::Kyber768)?;
+    let kyber_ss = decapsulate(KyberLevel::Kyber768, &kyber_ct, &secret.kyber)?;
+    let mut x25519_ss = secret.x25519.diffie_hellman(&peer_ephemeral).to_bytes();
+
+    let combined = combine_secrets(&x25519_ss, kyber_ss.as_bytes());
+    x25519_ss.zeroize();
+
+    Ok(combined)
+}
+
+/// `SHAKE256(x25519_ss || kyber_ss)` truncated to 32 bytes.
+fn combine_secrets(x25519_ss: &[u8; 32], kyber_ss: &[u8]) -> SharedSecret {
+    let mut hasher = Shake256::default();
+    hasher.update(x25519_ss);
+    hasher.update(kyber_ss);
+
+    let mut out = [0u8; crate::CRYPTO_BYTES];
+    hasher.finalize_xof().read(&mut out);
+    out.into()
+}
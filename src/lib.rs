@@ -1,189 +1,503 @@
-//! # Kyber-Rust
-//!
-//! A Rust wrapper for the Kyber post-quantum key encapsulation mechanism.
-//!
-//! This crate provides a safe Rust interface to the Kyber algorithm, which is a
-//! finalist in the NIST Post-Quantum Cryptography standardization process.
-//!
-//! ## Example
-//!
-//! ```rust
-//! use kyber_rust::{generate_keypair, encapsulate, decapsulate};
-//!
-//! // Generate a keypair
-//! let (public_key, secret_key) = generate_keypair().unwrap();
-//!
-//! // Encapsulate a shared secret
-//! let (ciphertext, shared_secret_enc) = encapsulate(&public_key).unwrap();
-//!
-//! // Decapsulate the shared secret
-//! let shared_secret_dec = decapsulate(&ciphertext, &secret_key).unwrap();
-//!
-//! // Verify that the shared secrets match
-//! assert_eq!(shared_secret_enc, shared_secret_dec);
-//! ```
-
-use libloading::{Library, Symbol};
-use std::os::raw::c_int;
-use std::sync::Once;
-
-pub const CRYPTO_PUBLICKEYBYTES: usize = 1184;
-pub const CRYPTO_SECRETKEYBYTES: usize = 2400;
-pub const CRYPTO_CIPHERTEXTBYTES: usize = 1088;
-pub const CRYPTO_BYTES: usize = 32;
-
-static INIT: Once = Once::new();
-static mut LIBRARY: Option<Library> = None;
-
-fn load_library() -> &'static Library {
-    INIT.call_once(|| unsafe {
-        LIBRARY = Some(Library::new("kyber.dll").expect("Failed to load kyber.dll"));
-    });
-    unsafe { LIBRARY.as_ref().unwrap() }
-}
-
-/// Generates a Kyber keypair.
-///
-/// Returns a tuple containing the public key and secret key.
-pub fn generate_keypair(
-) -> Result<([u8; CRYPTO_PUBLICKEYBYTES], [u8; CRYPTO_SECRETKEYBYTES]), String> {
-    let mut pk = [0u8; CRYPTO_PUBLICKEYBYTES];
-    let mut sk = [0u8; CRYPTO_SECRETKEYBYTES];
-
-    let result = crypto_kem_keypair(&mut pk, &mut sk);
-    if result != 0 {
-        return Err(format!(
-            "Keypair generation failed with error code: {}",
-            result
-        ));
-    }
-
-    Ok((pk, sk))
-}
-
-/// Encapsulates a shared secret using a public key.
-///
-/// Returns a tuple containing the ciphertext and the encapsulated shared secret.
-pub fn encapsulate(
-    pk: &[u8; CRYPTO_PUBLICKEYBYTES],
-) -> Result<([u8; CRYPTO_CIPHERTEXTBYTES], [u8; CRYPTO_BYTES]), String> {
-    let mut ct = [0u8; CRYPTO_CIPHERTEXTBYTES];
-    let mut ss = [0u8; CRYPTO_BYTES];
-
-    let result = crypto_kem_enc(&mut ct, &mut ss, pk);
-    if result != 0 {
-        return Err(format!("Encapsulation failed with error code: {}", result));
-    }
-
-    Ok((ct, ss))
-}
-
-/// Decapsulates a shared secret using a ciphertext and a secret key.
-///
-/// Returns the decapsulated shared secret.
-pub fn decapsulate(
-    ct: &[u8; CRYPTO_CIPHERTEXTBYTES],
-    sk: &[u8; CRYPTO_SECRETKEYBYTES],
-) -> Result<[u8; CRYPTO_BYTES], String> {
-    let mut ss = [0u8; CRYPTO_BYTES];
-
-    let result = crypto_kem_dec(&mut ss, ct, sk);
-    if result != 0 {
-        return Err(format!("Decapsulation failed with error code: {}", result));
-    }
-
-    Ok(ss)
-}
-
-fn crypto_kem_keypair(
-    pk: &mut [u8; CRYPTO_PUBLICKEYBYTES],
-    sk: &mut [u8; CRYPTO_SECRETKEYBYTES],
-) -> i32 {
-    let lib = load_library();
-    unsafe {
-        let func: Symbol<unsafe extern "C" fn(*mut u8, *mut u8) -> c_int> = lib
-            .get(b"pqcrystals_kyber768_ref_keypair")
-            .expect("Failed to load keypair function");
-        func(pk.as_mut_ptr(), sk.as_mut_ptr())
-    }
-}
-
-fn crypto_kem_enc(
-    ct: &mut [u8; CRYPTO_CIPHERTEXTBYTES],
-    ss: &mut [u8; CRYPTO_BYTES],
-    pk: &[u8; CRYPTO_PUBLICKEYBYTES],
-) -> i32 {
-    let lib = load_library();
-    unsafe {
-        let func: Symbol<unsafe extern "C" fn(*mut u8, *mut u8, *const u8) -> c_int> = lib
-            .get(b"pqcrystals_kyber768_ref_enc")
-            .expect("Failed to load enc function");
-        func(ct.as_mut_ptr(), ss.as_mut_ptr(), pk.as_ptr())
-    }
-}
-
-fn crypto_kem_dec(
-    ss: &mut [u8; CRYPTO_BYTES],
-    ct: &[u8; CRYPTO_CIPHERTEXTBYTES],
-    sk: &[u8; CRYPTO_SECRETKEYBYTES],
-) -> i32 {
-    let lib = load_library();
-    unsafe {
-        let func: Symbol<unsafe extern "C" fn(*mut u8, *const u8, *const u8) -> c_int> = lib
-            .get(b"pqcrystals_kyber768_ref_dec")
-            .expect("Failed to load dec function");
-        func(ss.as_mut_ptr(), ct.as_ptr(), sk.as_ptr())
-    }
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_generate_keypair() {
-        let (pk, sk) = generate_keypair().unwrap();
-        assert_eq!(pk.len(), CRYPTO_PUBLICKEYBYTES);
-        assert_eq!(sk.len(), CRYPTO_SECRETKEYBYTES);
-    }
-
-    #[test]
-    fn test_encapsulate() {
-        let (pk, _) = generate_keypair().unwrap();
-        let (ct, ss) = encapsulate(&pk).unwrap();
-        assert_eq!(ct.len(), CRYPTO_CIPHERTEXTBYTES);
-        assert_eq!(ss.len(), CRYPTO_BYTES);
-    }
-
-    #[test]
-    fn test_decapsulate() {
-        let (pk, sk) = generate_keypair().unwrap();
-        let (ct, ss_enc) = encapsulate(&pk).unwrap();
-        let ss_dec = decapsulate(&ct, &sk).unwrap();
-        assert_eq!(ss_enc, ss_dec);
-    }
-
-    #[test]
-    fn test_invalid_decapsulation() {
-        let (pk1, _sk1) = generate_keypair().unwrap();
-        let (_, sk2) = generate_keypair().unwrap();
-        let (ct, ss_enc) = encapsulate(&pk1).unwrap();
-
-        // Attempt to decapsulate with wrong secret key
-        let ss_dec = decapsulate(&ct, &sk2).unwrap();
-
-        // The decapsulated secret should be different from the original
-        assert_ne!(ss_enc, ss_dec);
-    }
-
-    #[test]
-    fn test_multiple_encapsulations() {
-        let (pk, sk) = generate_keypair().unwrap();
-
-        for _ in 0..10 {
-            let (ct, ss_enc) = encapsulate(&pk).unwrap();
-            let ss_dec = decapsulate(&ct, &sk).unwrap();
-            assert_eq!(ss_enc, ss_dec);
-        }
-    }
-}
+//! # Kyber-Rust
+//!
+//! A Rust wrapper for the Kyber post-quantum key encapsulation mechanism.
+//!
+//! This crate provides a safe Rust interface to the Kyber algorithm, which is a
+//! finalist in the NIST Post-Quantum Cryptography standardization process.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use kyber_rust::{generate_keypair, encapsulate, decapsulate, KyberLevel};
+//!
+//! // Generate a keypair at the desired security level
+//! let keypair = generate_keypair(KyberLevel::Kyber768).unwrap();
+//!
+//! // Encapsulate a shared secret
+//! let (ciphertext, shared_secret_enc) = encapsulate(KyberLevel::Kyber768, &keypair.public).unwrap();
+//!
+//! // Decapsulate the shared secret
+//! let shared_secret_dec = decapsulate(KyberLevel::Kyber768, &ciphertext, &keypair.secret).unwrap();
+//!
+//! // Verify that the shared secrets match
+//! assert_eq!(shared_secret_enc, shared_secret_dec);
+//! ```
+//!
+//! ## Deterministic KEM / KAT support
+//!
+//! [`generate_keypair_deterministic`] and [`encapsulate_deterministic`] call
+//! the `_derand` variants of the reference library's entry points
+//! (`pqcrystals_kyber{level}_ref_{keypair,enc}_derand`). These were added to
+//! the pqcrystals Kyber `ref` implementation alongside its KAT generator
+//! support; a vendored library built from an older snapshot that only
+//! exports the non-`derand` symbols will work for [`generate_keypair`]/
+//! [`encapsulate`]/[`decapsulate`] but fail the deterministic calls with
+//! [`KyberError::SymbolMissing`]. Use [`deterministic_api_available`] to
+//! check up front.
+
+mod drbg;
+mod error;
+pub mod hybrid;
+mod types;
+
+pub use drbg::Aes256CtrDrbg;
+pub use error::KyberError;
+pub use types::{Ciphertext, Keypair, PublicKey, SecretKey, SharedSecret};
+
+use libloading::{Library, Symbol};
+use std::os::raw::c_int;
+use std::sync::OnceLock;
+
+/// Shared-secret size in bytes, identical across all three security levels.
+pub const CRYPTO_BYTES: usize = 32;
+
+/// The three standardized Kyber security levels.
+///
+/// Each level selects a different `pqcrystals_kyber{level}_ref_*` symbol in
+/// the underlying reference library and a different set of key/ciphertext
+/// sizes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum KyberLevel {
+    /// Kyber512, roughly equivalent to AES-128.
+    Kyber512,
+    /// Kyber768, roughly equivalent to AES-192. The historical default of this crate.
+    Kyber768,
+    /// Kyber1024, roughly equivalent to AES-256.
+    Kyber1024,
+}
+
+impl KyberLevel {
+    /// Size in bytes of the public key at this level.
+    pub const fn public_key_bytes(self) -> usize {
+        match self {
+            KyberLevel::Kyber512 => 800,
+            KyberLevel::Kyber768 => 1184,
+            KyberLevel::Kyber1024 => 1568,
+        }
+    }
+
+    /// Size in bytes of the secret key at this level.
+    pub const fn secret_key_bytes(self) -> usize {
+        match self {
+            KyberLevel::Kyber512 => 1632,
+            KyberLevel::Kyber768 => 2400,
+            KyberLevel::Kyber1024 => 3168,
+        }
+    }
+
+    /// Size in bytes of the ciphertext at this level.
+    pub const fn ciphertext_bytes(self) -> usize {
+        match self {
+            KyberLevel::Kyber512 => 768,
+            KyberLevel::Kyber768 => 1088,
+            KyberLevel::Kyber1024 => 1568,
+        }
+    }
+
+    fn keypair_symbol(self) -> &'static [u8] {
+        match self {
+            KyberLevel::Kyber512 => b"pqcrystals_kyber512_ref_keypair\0",
+            KyberLevel::Kyber768 => b"pqcrystals_kyber768_ref_keypair\0",
+            KyberLevel::Kyber1024 => b"pqcrystals_kyber1024_ref_keypair\0",
+        }
+    }
+
+    fn enc_symbol(self) -> &'static [u8] {
+        match self {
+            KyberLevel::Kyber512 => b"pqcrystals_kyber512_ref_enc\0",
+            KyberLevel::Kyber768 => b"pqcrystals_kyber768_ref_enc\0",
+            KyberLevel::Kyber1024 => b"pqcrystals_kyber1024_ref_enc\0",
+        }
+    }
+
+    fn dec_symbol(self) -> &'static [u8] {
+        match self {
+            KyberLevel::Kyber512 => b"pqcrystals_kyber512_ref_dec\0",
+            KyberLevel::Kyber768 => b"pqcrystals_kyber768_ref_dec\0",
+            KyberLevel::Kyber1024 => b"pqcrystals_kyber1024_ref_dec\0",
+        }
+    }
+
+    fn keypair_derand_symbol(self) -> &'static [u8] {
+        match self {
+            KyberLevel::Kyber512 => b"pqcrystals_kyber512_ref_keypair_derand\0",
+            KyberLevel::Kyber768 => b"pqcrystals_kyber768_ref_keypair_derand\0",
+            KyberLevel::Kyber1024 => b"pqcrystals_kyber1024_ref_keypair_derand\0",
+        }
+    }
+
+    fn enc_derand_symbol(self) -> &'static [u8] {
+        match self {
+            KyberLevel::Kyber512 => b"pqcrystals_kyber512_ref_enc_derand\0",
+            KyberLevel::Kyber768 => b"pqcrystals_kyber768_ref_enc_derand\0",
+            KyberLevel::Kyber1024 => b"pqcrystals_kyber1024_ref_enc_derand\0",
+        }
+    }
+}
+
+/// Size in bytes of the `coins` a deterministic keypair draws: one symmetric
+/// seed (`KYBER_SYMBYTES`) for the IND-CPA keypair, plus one for the
+/// pseudo-random rejection value `z`.
+pub const KEYPAIR_COINS_BYTES: usize = 2 * CRYPTO_BYTES;
+
+/// Size in bytes of the `coins` a deterministic encapsulation draws.
+pub const ENCAPSULATE_COINS_BYTES: usize = CRYPTO_BYTES;
+
+static LIBRARY: OnceLock<Result<Library, KyberError>> = OnceLock::new();
+
+/// Name of the environment variable that, if set, overrides the path to the
+/// Kyber reference shared library. Takes priority over every other lookup.
+pub const KYBER_LIB_PATH_ENV: &str = "KYBER_LIB_PATH";
+
+/// Platform-appropriate default filename for the Kyber reference shared library.
+#[cfg(target_os = "windows")]
+const DEFAULT_LIB_FILENAME: &str = "kyber.dll";
+#[cfg(target_os = "macos")]
+const DEFAULT_LIB_FILENAME: &str = "libkyber.dylib";
+#[cfg(all(unix, not(target_os = "macos")))]
+const DEFAULT_LIB_FILENAME: &str = "libkyber.so";
+
+/// Candidate paths to try, in order, when no `KYBER_LIB_PATH` override is set:
+/// the build output directory the shared library was copied into, then the
+/// bare filename (left to the system loader's search path).
+fn candidate_lib_paths() -> Vec<std::path::PathBuf> {
+    let mut candidates = Vec::new();
+    if let Some(out_dir) = option_env!("KYBER_BUILD_OUT_DIR") {
+        candidates.push(std::path::PathBuf::from(out_dir).join(DEFAULT_LIB_FILENAME));
+    }
+    candidates.push(std::path::PathBuf::from(DEFAULT_LIB_FILENAME));
+    candidates
+}
+
+fn load_library() -> Result<&'static Library, KyberError> {
+    LIBRARY
+        .get_or_init(|| {
+            if let Ok(override_path) = std::env::var(KYBER_LIB_PATH_ENV) {
+                return unsafe { Library::new(&override_path) }
+                    .map_err(|e| KyberError::LibraryLoadFailed(format!("{}: {}", override_path, e)));
+            }
+
+            let candidates = candidate_lib_paths();
+            let mut last_err = None;
+            for candidate in &candidates {
+                match unsafe { Library::new(candidate) } {
+                    Ok(lib) => return Ok(lib),
+                    Err(e) => last_err = Some(e),
+                }
+            }
+            Err(KyberError::LibraryLoadFailed(format!(
+                "tried {:?}: {}",
+                candidates,
+                last_err.unwrap()
+            )))
+        })
+        .as_ref()
+        .map_err(Clone::clone)
+}
+
+/// Generates a Kyber keypair at the given security level.
+pub fn generate_keypair(level: KyberLevel) -> Result<Keypair, KyberError> {
+    let mut pk = vec![0u8; level.public_key_bytes()];
+    let mut sk = vec![0u8; level.secret_key_bytes()];
+
+    let result = crypto_kem_keypair(level, &mut pk, &mut sk)?;
+    if result != 0 {
+        return Err(KyberError::KeypairGeneration(result));
+    }
+
+    Ok(Keypair {
+        public: PublicKey::from_bytes(&pk, level)?,
+        secret: SecretKey::from_bytes(&sk, level)?,
+    })
+}
+
+/// Encapsulates a shared secret using a public key at the given security level.
+///
+/// Returns a tuple containing the ciphertext and the encapsulated shared secret.
+pub fn encapsulate(level: KyberLevel, pk: &PublicKey) -> Result<(Ciphertext, SharedSecret), KyberError> {
+    if pk.as_bytes().len() != level.public_key_bytes() {
+        return Err(KyberError::InvalidInput(format!(
+            "invalid public key length: expected {}, got {}",
+            level.public_key_bytes(),
+            pk.as_bytes().len()
+        )));
+    }
+
+    let mut ct = vec![0u8; level.ciphertext_bytes()];
+    let mut ss = [0u8; CRYPTO_BYTES];
+
+    let result = crypto_kem_enc(level, &mut ct, &mut ss, pk.as_bytes())?;
+    if result != 0 {
+        return Err(KyberError::Encapsulation(result));
+    }
+
+    Ok((Ciphertext::from_bytes(&ct, level)?, ss.into()))
+}
+
+/// Decapsulates a shared secret using a ciphertext and a secret key at the given security level.
+///
+/// Returns the decapsulated shared secret.
+pub fn decapsulate(level: KyberLevel, ct: &Ciphertext, sk: &SecretKey) -> Result<SharedSecret, KyberError> {
+    if ct.as_bytes().len() != level.ciphertext_bytes() {
+        return Err(KyberError::InvalidInput(format!(
+            "invalid ciphertext length: expected {}, got {}",
+            level.ciphertext_bytes(),
+            ct.as_bytes().len()
+        )));
+    }
+    if sk.as_bytes().len() != level.secret_key_bytes() {
+        return Err(KyberError::InvalidInput(format!(
+            "invalid secret key length: expected {}, got {}",
+            level.secret_key_bytes(),
+            sk.as_bytes().len()
+        )));
+    }
+
+    let mut ss = [0u8; CRYPTO_BYTES];
+
+    let result = crypto_kem_dec(level, &mut ss, ct.as_bytes(), sk.as_bytes())?;
+    if result != 0 {
+        return Err(KyberError::Decapsulation(result));
+    }
+
+    Ok(ss.into())
+}
+
+/// Reports whether the loaded reference library exports the `_derand` entry
+/// points [`generate_keypair_deterministic`] and [`encapsulate_deterministic`]
+/// need.
+///
+/// The `_derand` symbols were added to the pqcrystals Kyber `ref`
+/// implementation alongside its KAT generator support; a vendored library
+/// built from an older snapshot that only exports `crypto_kem_keypair`/`_enc`/
+/// `_dec` will load fine for [`generate_keypair`]/[`encapsulate`]/
+/// [`decapsulate`] but fail the deterministic calls with
+/// [`KyberError::SymbolMissing`]. Call this first if you need to fail fast
+/// (e.g. before running a KAT suite) rather than discovering it on first use.
+pub fn deterministic_api_available(level: KyberLevel) -> bool {
+    let Ok(lib) = load_library() else { return false };
+    unsafe {
+        lib.get::<unsafe extern "C" fn(*mut u8, *mut u8, *const u8) -> c_int>(level.keypair_derand_symbol())
+            .is_ok()
+            && lib
+                .get::<unsafe extern "C" fn(*mut u8, *mut u8, *const u8, *const u8) -> c_int>(
+                    level.enc_derand_symbol(),
+                )
+                .is_ok()
+    }
+}
+
+/// Generates a Kyber keypair from caller-supplied `coins` instead of the
+/// library's internal RNG.
+///
+/// `coins` must be exactly [`KEYPAIR_COINS_BYTES`] long: this is the
+/// `crypto_kem_keypair_derand` entry point the NIST KAT generator's
+/// `crypto_kem_keypair` itself forwards to after drawing its randomness, so
+/// feeding it precomputed coins reproduces a KAT vector byte-for-byte. See
+/// [`Aes256CtrDrbg`] for deriving `coins` from a KAT `seed`.
+///
+/// Requires a reference library that exports the `_derand` entry points (see
+/// [`deterministic_api_available`]); if it doesn't, this returns
+/// [`KyberError::SymbolMissing`] rather than silently falling back to
+/// non-deterministic keygen.
+pub fn generate_keypair_deterministic(level: KyberLevel, coins: &[u8]) -> Result<Keypair, KyberError> {
+    if coins.len() != KEYPAIR_COINS_BYTES {
+        return Err(KyberError::InvalidInput(format!(
+            "invalid keypair coins length: expected {}, got {}",
+            KEYPAIR_COINS_BYTES,
+            coins.len()
+        )));
+    }
+
+    let mut pk = vec![0u8; level.public_key_bytes()];
+    let mut sk = vec![0u8; level.secret_key_bytes()];
+
+    let result = crypto_kem_keypair_derand(level, &mut pk, &mut sk, coins)?;
+    if result != 0 {
+        return Err(KyberError::KeypairGeneration(result));
+    }
+
+    Ok(Keypair {
+        public: PublicKey::from_bytes(&pk, level)?,
+        secret: SecretKey::from_bytes(&sk, level)?,
+    })
+}
+
+/// Encapsulates a shared secret from caller-supplied `coins` instead of the
+/// library's internal RNG.
+///
+/// `coins` must be exactly [`ENCAPSULATE_COINS_BYTES`] long; see
+/// [`generate_keypair_deterministic`] for why this reproduces KAT vectors,
+/// and for the `_derand` library requirement this call shares.
+pub fn encapsulate_deterministic(
+    level: KyberLevel,
+    pk: &PublicKey,
+    coins: &[u8],
+) -> Result<(Ciphertext, SharedSecret), KyberError> {
+    if pk.as_bytes().len() != level.public_key_bytes() {
+        return Err(KyberError::InvalidInput(format!(
+            "invalid public key length: expected {}, got {}",
+            level.public_key_bytes(),
+            pk.as_bytes().len()
+        )));
+    }
+    if coins.len() != ENCAPSULATE_COINS_BYTES {
+        return Err(KyberError::InvalidInput(format!(
+            "invalid encapsulate coins length: expected {}, got {}",
+            ENCAPSULATE_COINS_BYTES,
+            coins.len()
+        )));
+    }
+
+    let mut ct = vec![0u8; level.ciphertext_bytes()];
+    let mut ss = [0u8; CRYPTO_BYTES];
+
+    let result = crypto_kem_enc_derand(level, &mut ct, &mut ss, pk.as_bytes(), coins)?;
+    if result != 0 {
+        return Err(KyberError::Encapsulation(result));
+    }
+
+    Ok((Ciphertext::from_bytes(&ct, level)?, ss.into()))
+}
+
+fn crypto_kem_keypair(level: KyberLevel, pk: &mut [u8], sk: &mut [u8]) -> Result<i32, KyberError> {
+    let lib = load_library()?;
+    unsafe {
+        let func: Symbol<unsafe extern "C" fn(*mut u8, *mut u8) -> c_int> = lib
+            .get(level.keypair_symbol())
+            .map_err(|_| KyberError::SymbolMissing(symbol_name(level.keypair_symbol())))?;
+        Ok(func(pk.as_mut_ptr(), sk.as_mut_ptr()))
+    }
+}
+
+fn crypto_kem_enc(
+    level: KyberLevel,
+    ct: &mut [u8],
+    ss: &mut [u8; CRYPTO_BYTES],
+    pk: &[u8],
+) -> Result<i32, KyberError> {
+    let lib = load_library()?;
+    unsafe {
+        let func: Symbol<unsafe extern "C" fn(*mut u8, *mut u8, *const u8) -> c_int> = lib
+            .get(level.enc_symbol())
+            .map_err(|_| KyberError::SymbolMissing(symbol_name(level.enc_symbol())))?;
+        Ok(func(ct.as_mut_ptr(), ss.as_mut_ptr(), pk.as_ptr()))
+    }
+}
+
+fn crypto_kem_dec(
+    level: KyberLevel,
+    ss: &mut [u8; CRYPTO_BYTES],
+    ct: &[u8],
+    sk: &[u8],
+) -> Result<i32, KyberError> {
+    let lib = load_library()?;
+    unsafe {
+        let func: Symbol<unsafe extern "C" fn(*mut u8, *const u8, *const u8) -> c_int> = lib
+            .get(level.dec_symbol())
+            .map_err(|_| KyberError::SymbolMissing(symbol_name(level.dec_symbol())))?;
+        Ok(func(ss.as_mut_ptr(), ct.as_ptr(), sk.as_ptr()))
+    }
+}
+
+fn crypto_kem_keypair_derand(
+    level: KyberLevel,
+    pk: &mut [u8],
+    sk: &mut [u8],
+    coins: &[u8],
+) -> Result<i32, KyberError> {
+    let lib = load_library()?;
+    unsafe {
+        let func: Symbol<unsafe extern "C" fn(*mut u8, *mut u8, *const u8) -> c_int> = lib
+            .get(level.keypair_derand_symbol())
+            .map_err(|_| KyberError::SymbolMissing(symbol_name(level.keypair_derand_symbol())))?;
+        Ok(func(pk.as_mut_ptr(), sk.as_mut_ptr(), coins.as_ptr()))
+    }
+}
+
+fn crypto_kem_enc_derand(
+    level: KyberLevel,
+    ct: &mut [u8],
+    ss: &mut [u8; CRYPTO_BYTES],
+    pk: &[u8],
+    coins: &[u8],
+) -> Result<i32, KyberError> {
+    let lib = load_library()?;
+    unsafe {
+        let func: Symbol<unsafe extern "C" fn(*mut u8, *mut u8, *const u8, *const u8) -> c_int> =
+            lib.get(level.enc_derand_symbol())
+                .map_err(|_| KyberError::SymbolMissing(symbol_name(level.enc_derand_symbol())))?;
+        Ok(func(ct.as_mut_ptr(), ss.as_mut_ptr(), pk.as_ptr(), coins.as_ptr()))
+    }
+}
+
+/// Renders a NUL-terminated symbol name byte string as a readable `String` for error messages.
+fn symbol_name(symbol: &[u8]) -> String {
+    String::from_utf8_lossy(symbol.strip_suffix(b"\0").unwrap_or(symbol)).into_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LEVELS: [KyberLevel; 3] = [
+        KyberLevel::Kyber512,
+        KyberLevel::Kyber768,
+        KyberLevel::Kyber1024,
+    ];
+
+    #[test]
+    fn test_generate_keypair() {
+        for level in LEVELS {
+            let keypair = generate_keypair(level).unwrap();
+            assert_eq!(keypair.public.as_bytes().len(), level.public_key_bytes());
+            assert_eq!(keypair.secret.as_bytes().len(), level.secret_key_bytes());
+        }
+    }
+
+    #[test]
+    fn test_encapsulate() {
+        for level in LEVELS {
+            let keypair = generate_keypair(level).unwrap();
+            let (ct, ss) = encapsulate(level, &keypair.public).unwrap();
+            assert_eq!(ct.as_bytes().len(), level.ciphertext_bytes());
+            assert_eq!(ss.as_bytes().len(), CRYPTO_BYTES);
+        }
+    }
+
+    #[test]
+    fn test_decapsulate() {
+        for level in LEVELS {
+            let keypair = generate_keypair(level).unwrap();
+            let (ct, ss_enc) = encapsulate(level, &keypair.public).unwrap();
+            let ss_dec = decapsulate(level, &ct, &keypair.secret).unwrap();
+            assert_eq!(ss_enc, ss_dec);
+        }
+    }
+
+    #[test]
+    fn test_invalid_decapsulation() {
+        let level = KyberLevel::Kyber768;
+        let keypair1 = generate_keypair(level).unwrap();
+        let keypair2 = generate_keypair(level).unwrap();
+        let (ct, ss_enc) = encapsulate(level, &keypair1.public).unwrap();
+
+        // Attempt to decapsulate with wrong secret key
+        let ss_dec = decapsulate(level, &ct, &keypair2.secret).unwrap();
+
+        // The decapsulated secret should be different from the original
+        assert_ne!(ss_enc, ss_dec);
+    }
+
+    #[test]
+    fn test_multiple_encapsulations() {
+        let level = KyberLevel::Kyber768;
+        let keypair = generate_keypair(level).unwrap();
+
+        for _ in 0..10 {
+            let (ct, ss_enc) = encapsulate(level, &keypair.public).unwrap();
+            let ss_dec = decapsulate(level, &ct, &keypair.secret).unwrap();
+            assert_eq!(ss_enc, ss_dec);
+        }
+    }
+}
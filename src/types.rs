@@ -0,0 +1,327 @@
+//! Typed wrappers around the raw byte buffers exchanged by the Kyber KEM.
+//!
+//! Plain `Vec<u8>`/`[u8; N]` buffers don't stop a caller from, say, passing a
+//! ciphertext where a public key is expected. [`PublicKey`], [`SecretKey`],
+//! [`Ciphertext`], and [`SharedSecret`] give each buffer its own type, plus
+//! `to_hex`/`from_hex` and `to_base64`/`from_base64` for storing them in
+//! config files or JSON transports. Enable the `serde` feature for
+//! `Serialize`/`Deserialize` impls (as a hex string).
+
+use crate::{KyberError, KyberLevel, CRYPTO_BYTES};
+
+#[cfg(feature = "serde")]
+use serde::{de::Error as _, Deserialize, Deserializer, Serialize, Serializer};
+
+const HEX_CHARS: &[u8; 16] = b"0123456789abcdef";
+const BASE64_CHARS: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+fn encode_hex(bytes: &[u8]) -> String {
+    let mut out = String::with_capacity(bytes.len() * 2);
+    for b in bytes {
+        out.push(HEX_CHARS[(b >> 4) as usize] as char);
+        out.push(HEX_CHARS[(b & 0x0f) as usize] as char);
+    }
+    out
+}
+
+fn hex_digit(b: u8) -> Result<u8, KyberError> {
+    match b {
+        b'0'..=b'9' => Ok(b - b'0'),
+        b'a'..=b'f' => Ok(b - b'a' + 10),
+        b'A'..=b'F' => Ok(b - b'A' + 10),
+        _ => Err(KyberError::InvalidInput(format!(
+            "invalid hex character: {:?}",
+            b as char
+        ))),
+    }
+}
+
+fn decode_hex(s: &str) -> Result<Vec<u8>, KyberError> {
+    // Operate on raw bytes, not `str` byte-index slicing: a `str` slice at a
+    // non-UTF-8-boundary index panics, which non-ASCII untrusted input (e.g.
+    // config files / JSON transports) can trigger even after a length check.
+    let bytes = s.trim().as_bytes();
+    if !bytes.len().is_multiple_of(2) {
+        return Err(KyberError::InvalidInput(format!(
+            "hex string has odd length: {}",
+            bytes.len()
+        )));
+    }
+    bytes
+        .chunks_exact(2)
+        .map(|pair| Ok((hex_digit(pair[0])? << 4) | hex_digit(pair[1])?))
+        .collect()
+}
+
+fn encode_base64(bytes: &[u8]) -> String {
+    let mut out = String::with_capacity(bytes.len().div_ceil(3) * 4);
+    for chunk in bytes.chunks(3) {
+        let b0 = chunk[0];
+        let b1 = *chunk.get(1).unwrap_or(&0);
+        let b2 = *chunk.get(2).unwrap_or(&0);
+
+        out.push(BASE64_CHARS[(b0 >> 2) as usize] as char);
+        out.push(BASE64_CHARS[(((b0 & 0x03) << 4) | (b1 >> 4)) as usize] as char);
+        out.push(if chunk.len() > 1 {
+            BASE64_CHARS[(((b1 & 0x0f) << 2) | (b2 >> 6)) as usize] as char
+        } else {
+            '='
+        });
+        out.push(if chunk.len() > 2 {
+            BASE64_CHARS[(b2 & 0x3f) as usize] as char
+        } else {
+            '='
+        });
+    }
+    out
+}
+
+fn base64_value(c: u8) -> Result<u8, KyberError> {
+    BASE64_CHARS
+        .iter()
+        .position(|&x| x == c)
+        .map(|p| p as u8)
+        .ok_or_else(|| KyberError::InvalidInput(format!("invalid base64 character: {:?}", c as char)))
+}
+
+fn decode_base64(s: &str) -> Result<Vec<u8>, KyberError> {
+    let s = s.trim().trim_end_matches('=');
+    let input: Vec<u8> = s.bytes().collect();
+    if input.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let mut out = Vec::with_capacity(input.len() * 3 / 4);
+    for chunk in input.chunks(4) {
+        let vals: Vec<u8> = chunk
+            .iter()
+            .map(|&c| base64_value(c))
+            .collect::<Result<_, _>>()?;
+
+        out.push((vals[0] << 2) | (vals.get(1).unwrap_or(&0) >> 4));
+        if vals.len() > 2 {
+            out.push((vals[1] << 4) | (vals[2] >> 2));
+        }
+        if vals.len() > 3 {
+            out.push((vals[2] << 6) | vals[3]);
+        }
+    }
+    Ok(out)
+}
+
+fn check_len(what: &str, bytes: &[u8], expected: usize) -> Result<(), KyberError> {
+    if bytes.len() != expected {
+        return Err(KyberError::InvalidInput(format!(
+            "invalid {} length: expected {}, got {}",
+            what,
+            expected,
+            bytes.len()
+        )));
+    }
+    Ok(())
+}
+
+/// A Kyber public key, sized according to the [`KyberLevel`] it was generated at.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PublicKey(Vec<u8>);
+
+/// A Kyber secret key, sized according to the [`KyberLevel`] it was generated at.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SecretKey(Vec<u8>);
+
+/// A Kyber KEM ciphertext, sized according to the [`KyberLevel`] it was produced at.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Ciphertext(Vec<u8>);
+
+/// The 32-byte shared secret agreed by encapsulation and decapsulation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SharedSecret([u8; CRYPTO_BYTES]);
+
+macro_rules! impl_common {
+    ($name:ident) => {
+        impl $name {
+            /// Borrows the raw bytes.
+            pub fn as_bytes(&self) -> &[u8] {
+                &self.0
+            }
+
+            /// Consumes the wrapper, returning the raw bytes.
+            pub fn into_bytes(self) -> Vec<u8> {
+                self.0.into()
+            }
+
+            /// Encodes the raw bytes as lowercase hex.
+            pub fn to_hex(&self) -> String {
+                encode_hex(&self.0)
+            }
+
+            /// Encodes the raw bytes as standard (padded) base64.
+            pub fn to_base64(&self) -> String {
+                encode_base64(&self.0)
+            }
+        }
+    };
+}
+
+impl_common!(PublicKey);
+impl_common!(SecretKey);
+impl_common!(Ciphertext);
+impl_common!(SharedSecret);
+
+impl PublicKey {
+    /// Wraps `bytes` as a public key, validating its length against `level`.
+    pub fn from_bytes(bytes: &[u8], level: KyberLevel) -> Result<Self, KyberError> {
+        check_len("public key", bytes, level.public_key_bytes())?;
+        Ok(PublicKey(bytes.to_vec()))
+    }
+
+    /// Decodes a hex-encoded public key, validating its length against `level`.
+    pub fn from_hex(hex: &str, level: KyberLevel) -> Result<Self, KyberError> {
+        Self::from_bytes(&decode_hex(hex)?, level)
+    }
+
+    /// Decodes a base64-encoded public key, validating its length against `level`.
+    pub fn from_base64(b64: &str, level: KyberLevel) -> Result<Self, KyberError> {
+        Self::from_bytes(&decode_base64(b64)?, level)
+    }
+}
+
+impl SecretKey {
+    /// Wraps `bytes` as a secret key, validating its length against `level`.
+    pub fn from_bytes(bytes: &[u8], level: KyberLevel) -> Result<Self, KyberError> {
+        check_len("secret key", bytes, level.secret_key_bytes())?;
+        Ok(SecretKey(bytes.to_vec()))
+    }
+
+    /// Decodes a hex-encoded secret key, validating its length against `level`.
+    pub fn from_hex(hex: &str, level: KyberLevel) -> Result<Self, KyberError> {
+        Self::from_bytes(&decode_hex(hex)?, level)
+    }
+
+    /// Decodes a base64-encoded secret key, validating its length against `level`.
+    pub fn from_base64(b64: &str, level: KyberLevel) -> Result<Self, KyberError> {
+        Self::from_bytes(&decode_base64(b64)?, level)
+    }
+}
+
+impl Ciphertext {
+    /// Wraps `bytes` as a ciphertext, validating its length against `level`.
+    pub fn from_bytes(bytes: &[u8], level: KyberLevel) -> Result<Self, KyberError> {
+        check_len("ciphertext", bytes, level.ciphertext_bytes())?;
+        Ok(Ciphertext(bytes.to_vec()))
+    }
+
+    /// Decodes a hex-encoded ciphertext, validating its length against `level`.
+    pub fn from_hex(hex: &str, level: KyberLevel) -> Result<Self, KyberError> {
+        Self::from_bytes(&decode_hex(hex)?, level)
+    }
+
+    /// Decodes a base64-encoded ciphertext, validating its length against `level`.
+    pub fn from_base64(b64: &str, level: KyberLevel) -> Result<Self, KyberError> {
+        Self::from_bytes(&decode_base64(b64)?, level)
+    }
+}
+
+impl SharedSecret {
+    /// Wraps `bytes` as a shared secret; always [`CRYPTO_BYTES`] long.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, KyberError> {
+        check_len("shared secret", bytes, CRYPTO_BYTES)?;
+        let mut buf = [0u8; CRYPTO_BYTES];
+        buf.copy_from_slice(bytes);
+        Ok(SharedSecret(buf))
+    }
+
+    /// Decodes a hex-encoded shared secret.
+    pub fn from_hex(hex: &str) -> Result<Self, KyberError> {
+        Self::from_bytes(&decode_hex(hex)?)
+    }
+
+    /// Decodes a base64-encoded shared secret.
+    pub fn from_base64(b64: &str) -> Result<Self, KyberError> {
+        Self::from_bytes(&decode_base64(b64)?)
+    }
+}
+
+impl From<[u8; CRYPTO_BYTES]> for SharedSecret {
+    fn from(bytes: [u8; CRYPTO_BYTES]) -> Self {
+        SharedSecret(bytes)
+    }
+}
+
+/// A matched Kyber public/secret keypair, mirroring the ergonomics of the
+/// `pqc_kyber` crate's `Keypair`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Keypair {
+    pub public: PublicKey,
+    pub secret: SecretKey,
+}
+
+// `PublicKey`/`SecretKey`/`Ciphertext`/`SharedSecret` serialize as a hex
+// string. `PublicKey`/`SecretKey`/`Ciphertext` can't validate against a
+// `KyberLevel` during deserialization (the level isn't part of the wire
+// format), so callers should still re-validate with `from_bytes` against the
+// level they expect after deserializing.
+#[cfg(feature = "serde")]
+macro_rules! impl_serde_as_hex {
+    ($name:ident, $wrap:expr) => {
+        impl Serialize for $name {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.serialize_str(&self.to_hex())
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $name {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                let hex = String::deserialize(deserializer)?;
+                let bytes = decode_hex(&hex).map_err(D::Error::custom)?;
+                $wrap(bytes).map_err(D::Error::custom)
+            }
+        }
+    };
+}
+
+#[cfg(feature = "serde")]
+impl_serde_as_hex!(PublicKey, |bytes: Vec<u8>| Ok::<_, KyberError>(PublicKey(bytes)));
+#[cfg(feature = "serde")]
+impl_serde_as_hex!(SecretKey, |bytes: Vec<u8>| Ok::<_, KyberError>(SecretKey(bytes)));
+#[cfg(feature = "serde")]
+impl_serde_as_hex!(Ciphertext, |bytes: Vec<u8>| Ok::<_, KyberError>(Ciphertext(bytes)));
+#[cfg(feature = "serde")]
+impl_serde_as_hex!(SharedSecret, |bytes: Vec<u8>| SharedSecret::from_bytes(&bytes));
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hex_roundtrip() {
+        let level = KyberLevel::Kyber768;
+        let bytes = vec![0u8; level.public_key_bytes()];
+        let pk = PublicKey::from_bytes(&bytes, level).unwrap();
+        let hex = pk.to_hex();
+        let pk2 = PublicKey::from_hex(&hex, level).unwrap();
+        assert_eq!(pk, pk2);
+    }
+
+    #[test]
+    fn test_base64_roundtrip() {
+        let secret = SharedSecret::from_bytes(&[7u8; CRYPTO_BYTES]).unwrap();
+        let b64 = secret.to_base64();
+        let secret2 = SharedSecret::from_base64(&b64).unwrap();
+        assert_eq!(secret, secret2);
+    }
+
+    #[test]
+    fn test_invalid_length_rejected() {
+        let level = KyberLevel::Kyber512;
+        let err = PublicKey::from_bytes(&[0u8; 4], level).unwrap_err();
+        assert!(matches!(err, KyberError::InvalidInput(_)));
+    }
+
+    #[test]
+    fn test_decode_hex_rejects_non_ascii_instead_of_panicking() {
+        let level = KyberLevel::Kyber512;
+        let err = PublicKey::from_hex("\u{20ac}0", level).unwrap_err();
+        assert!(matches!(err, KyberError::InvalidInput(_)));
+    }
+}
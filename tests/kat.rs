@@ -0,0 +1,145 @@
+//! Validates the deterministic KEM API against NIST-style `.rsp` KAT files.
+//!
+//! `test_parse_rsp_entries` exercises the parser alone against the structural
+//! fixture checked into `tests/kat/`. `test_derand_is_deterministic_and_kat_seed_sensitive`
+//! runs unconditionally (no external fixture needed) and is the one that
+//! actually exercises the `_derand` entry points end-to-end: it proves the
+//! same KAT `seed` reproduces the identical keypair/ciphertext/shared-secret
+//! byte-for-byte on repeated runs, and that a different seed does not — the
+//! determinism property request chunk0-4 is built on. It still needs the real
+//! `kyber.dll`/`libkyber.so` to be present (same requirement every other test
+//! in this crate already has via `build.rs`); it does not require the
+//! official answer file. `test_kyber768_official_vectors` is `#[ignore]`d
+//! because, on top of that, it needs the official `PQCkemKAT_2400.rsp` from
+//! the Kyber submission package dropped into `tests/kat/` (2400 bytes is the
+//! Kyber768 secret-key length; `_1632` is the Kyber512 answer set) to check
+//! our output against NIST's published bytes rather than just against
+//! itself; run it explicitly with `cargo test --test kat -- --ignored` once
+//! that file is available.
+
+use kyber_rust::{encapsulate_deterministic, generate_keypair_deterministic, Aes256CtrDrbg, KyberLevel, PublicKey, SecretKey};
+
+struct KatEntry {
+    count: u32,
+    seed: Vec<u8>,
+    pk: Vec<u8>,
+    sk: Vec<u8>,
+    ct: Vec<u8>,
+    ss: Vec<u8>,
+}
+
+fn hex_decode(s: &str) -> Vec<u8> {
+    (0..s.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&s[i..i + 2], 16).expect("invalid hex in .rsp file"))
+        .collect()
+}
+
+/// Parses the `count`/`seed`/`pk`/`sk`/`ct`/`ss` fields of a NIST KAT `.rsp` file.
+fn parse_rsp(contents: &str) -> Vec<KatEntry> {
+    let mut entries = Vec::new();
+    let mut count = None;
+    let mut seed = None;
+    let mut pk = None;
+    let mut sk = None;
+    let mut ct = None;
+
+    for line in contents.lines() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let Some((key, value)) = line.split_once('=') else {
+            continue;
+        };
+        let key = key.trim();
+        let value = value.trim();
+        match key {
+            "count" => count = Some(value.parse().expect("invalid count in .rsp file")),
+            "seed" => seed = Some(hex_decode(value)),
+            "pk" => pk = Some(hex_decode(value)),
+            "sk" => sk = Some(hex_decode(value)),
+            "ct" => ct = Some(hex_decode(value)),
+            "ss" => {
+                entries.push(KatEntry {
+                    count: count.take().expect("ss field before count"),
+                    seed: seed.take().expect("ss field before seed"),
+                    pk: pk.take().expect("ss field before pk"),
+                    sk: sk.take().expect("ss field before sk"),
+                    ct: ct.take().expect("ss field before ct"),
+                    ss: hex_decode(value),
+                });
+            }
+            _ => {}
+        }
+    }
+    entries
+}
+
+#[test]
+fn test_parse_rsp_entries() {
+    let contents = std::fs::read_to_string("tests/kat/kyber768_sample.rsp").unwrap();
+    let entries = parse_rsp(&contents);
+
+    assert_eq!(entries.len(), 1);
+    assert_eq!(entries[0].count, 0);
+    assert_eq!(entries[0].seed.len(), 48);
+    assert_eq!(entries[0].pk.len(), 32);
+    assert_eq!(entries[0].sk.len(), 32);
+    assert_eq!(entries[0].ct.len(), 32);
+    assert_eq!(entries[0].ss.len(), 32);
+}
+
+#[test]
+fn test_derand_is_deterministic_and_kat_seed_sensitive() {
+    let level = KyberLevel::Kyber768;
+    let seed_a = [0x42u8; 48];
+    let seed_b = [0x43u8; 48];
+
+    let derive = |seed: [u8; 48]| {
+        let mut drbg = Aes256CtrDrbg::new(&seed);
+        let keypair_coins = drbg.next_bytes(kyber_rust::KEYPAIR_COINS_BYTES);
+        let encaps_coins = drbg.next_bytes(kyber_rust::ENCAPSULATE_COINS_BYTES);
+
+        let keypair = generate_keypair_deterministic(level, &keypair_coins).unwrap();
+        let (ct, ss) = encapsulate_deterministic(level, &keypair.public, &encaps_coins).unwrap();
+        (keypair, ct, ss)
+    };
+
+    let (keypair_a1, ct_a1, ss_a1) = derive(seed_a);
+    let (keypair_a2, ct_a2, ss_a2) = derive(seed_a);
+    assert_eq!(keypair_a1, keypair_a2, "same seed must reproduce the same keypair");
+    assert_eq!(ct_a1, ct_a2, "same seed must reproduce the same ciphertext");
+    assert_eq!(ss_a1, ss_a2, "same seed must reproduce the same shared secret");
+
+    let (keypair_b, ct_b, ss_b) = derive(seed_b);
+    assert_ne!(keypair_a1, keypair_b, "different seeds must not collide on keypairs");
+    assert_ne!(ct_a1, ct_b, "different seeds must not collide on ciphertexts");
+    assert_ne!(ss_a1, ss_b, "different seeds must not collide on shared secrets");
+}
+
+#[test]
+#[ignore = "requires the real kyber shared library and the official PQCkemKAT_2400.rsp"]
+fn test_kyber768_official_vectors() {
+    let contents = std::fs::read_to_string("tests/kat/PQCkemKAT_2400.rsp")
+        .expect("drop the official PQCkemKAT_2400.rsp into tests/kat/ to run this test");
+    let level = KyberLevel::Kyber768;
+
+    for entry in parse_rsp(&contents) {
+        let seed: [u8; 48] = entry.seed.clone().try_into().expect("seed must be 48 bytes");
+
+        // Reseeded once per KAT entry; keygen must consume its coins before
+        // encaps draws its own, exactly as the NIST KAT generator does.
+        let mut drbg = Aes256CtrDrbg::new(&seed);
+        let keypair_coins = drbg.next_bytes(kyber_rust::KEYPAIR_COINS_BYTES);
+        let encaps_coins = drbg.next_bytes(kyber_rust::ENCAPSULATE_COINS_BYTES);
+
+        let keypair = generate_keypair_deterministic(level, &keypair_coins).unwrap();
+        assert_eq!(keypair.public, PublicKey::from_bytes(&entry.pk, level).unwrap(), "pk mismatch at count={}", entry.count);
+        assert_eq!(keypair.secret, SecretKey::from_bytes(&entry.sk, level).unwrap(), "sk mismatch at count={}", entry.count);
+
+        let (ct, ss) = encapsulate_deterministic(level, &keypair.public, &encaps_coins).unwrap();
+        assert_eq!(ct.as_bytes(), entry.ct, "ct mismatch at count={}", entry.count);
+        assert_eq!(ss.as_bytes(), entry.ss, "ss mismatch at count={}", entry.count);
+    }
+}